@@ -11,12 +11,15 @@
 use prelude::*;
 use traits::{Std, OsString as OsStringT, OsStr as OsStrT};
 
+use ascii::AsciiExt;
 use borrow::{Borrow, Cow};
 use fmt;
 use ops;
 use cmp;
 use hash::{Hash, Hasher};
+use iter::FromIterator;
 use rc::Rc;
+use str;
 use alloc::arc::Arc;
 
 use sys_common::{AsInner, IntoInner, FromInner};
@@ -297,6 +300,77 @@ impl<STD: Std> OsString<STD> {
         self.inner.shrink_to_fit()
     }
 
+    /// Shrinks the capacity of the `OsString` with a lower bound.
+    ///
+    /// The capacity will remain at least as large as both the length and the
+    /// supplied value.
+    ///
+    /// If the current capacity is less than the lower limit, this is a no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsString;
+    ///
+    /// let mut s = OsString::from("foo");
+    ///
+    /// s.reserve(100);
+    /// assert!(s.capacity() >= 100);
+    ///
+    /// s.shrink_to(10);
+    /// assert!(s.capacity() >= 10);
+    /// s.shrink_to(0);
+    /// assert!(s.capacity() >= 3);
+    /// ```
+    // #[unstable(feature = "shrink_to", issue = "56431")]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.inner.shrink_to(min_capacity)
+    }
+
+    /// Shortens this `OsString` to `new_len` encoded bytes.
+    ///
+    /// `new_len` is measured in the same units as [`OsStr::as_encoded_bytes`],
+    /// which are not necessarily UTF-8 code units, UTF-16 code units, or
+    /// `char`s.
+    ///
+    /// If `new_len` is greater than the string's current length, this has no
+    /// effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` does not lie on an [`OsStr`] boundary.
+    ///
+    /// [`OsStr::as_encoded_bytes`]: struct.OsStr.html#method.as_encoded_bytes
+    // #[unstable(feature = "osstring_truncate", issue = "0")]
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len >= self.as_encoded_bytes().len() {
+            return;
+        }
+        assert!(self.inner.as_slice().is_encoded_boundary(new_len),
+                 "byte index {} is not an OsStr boundary", new_len);
+        self.inner.truncate(new_len)
+    }
+
+    /// Consumes and leaks the `OsString`, returning a mutable reference to the
+    /// contents, `&'static mut OsStr`.
+    ///
+    /// This is mainly useful for data that lives for the remainder of the
+    /// program's life. Dropping the returned reference will cause a memory leak.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::{OsString, OsStr};
+    ///
+    /// let x = OsString::from("bucket");
+    /// let static_ref: &'static mut OsStr = x.leak();
+    /// assert_eq!(static_ref, "bucket");
+    /// ```
+    // #[unstable(feature = "osstring_leak", issue = "0")]
+    pub fn leak(self) -> &'static mut OsStr<STD> {
+        Box::leak(self.into_boxed_os_str())
+    }
+
     /// Converts this `OsString` into a boxed [`OsStr`].
     ///
     /// [`OsStr`]: struct.OsStr.html
@@ -315,6 +389,36 @@ impl<STD: Std> OsString<STD> {
         let rw = Box::into_raw(self.inner.into_box()) as *mut OsStr<STD>;
         unsafe { Box::from_raw(rw) }
     }
+
+    /// Converts this `OsString` to its ASCII upper case equivalent in-place.
+    ///
+    /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', but non-ASCII letters
+    /// are unchanged.
+    ///
+    /// To return a new uppercased value without modifying the existing one, use
+    /// [`OsStr::to_ascii_uppercase`].
+    ///
+    /// [`OsStr::to_ascii_uppercase`]: struct.OsStr.html#method.to_ascii_uppercase
+    // #[unstable(feature = "osstring_ascii", issue = "0")]
+    pub fn make_ascii_uppercase(&mut self) {
+        // Safe because we only overwrite ASCII bytes with other ASCII bytes.
+        unsafe { self.inner.as_mut_bytes() }.make_ascii_uppercase()
+    }
+
+    /// Converts this `OsString` to its ASCII lower case equivalent in-place.
+    ///
+    /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', but non-ASCII letters
+    /// are unchanged.
+    ///
+    /// To return a new lowercased value without modifying the existing one, use
+    /// [`OsStr::to_ascii_lowercase`].
+    ///
+    /// [`OsStr::to_ascii_lowercase`]: struct.OsStr.html#method.to_ascii_lowercase
+    // #[unstable(feature = "osstring_ascii", issue = "0")]
+    pub fn make_ascii_lowercase(&mut self) {
+        // Safe because we only overwrite ASCII bytes with other ASCII bytes.
+        unsafe { self.inner.as_mut_bytes() }.make_ascii_lowercase()
+    }
 }
 
 // #[stable(feature = "rust1", since = "1.0.0")]
@@ -575,6 +679,128 @@ impl<STD: Std> OsStr<STD> {
     fn bytes(&self) -> &[u8] {
         self.inner.as_bytes()
     }
+
+    /// Converts this string to a slice of bytes.
+    ///
+    /// To convert the byte slice back into an `OsStr`, use the [`from_encoded_bytes_unchecked`]
+    /// function.
+    ///
+    /// The byte encoding is an unspecified, platform-specific, self-synchronizing superset of
+    /// UTF-8. Self-synchronizing means that if the bytes are split or concatenated along
+    /// non-adjacent boundaries returned by [`as_encoded_bytes`] on the respective substrings,
+    /// the concatenation is still the same as the one obtained by calling `as_encoded_bytes` on
+    /// the original, unsplit string. ASCII bytes always correspond to exactly themselves.
+    ///
+    /// Note that the encoding is implementation-defined, and may change between `STD` backends,
+    /// and even between releases of a given backend.
+    ///
+    /// [`as_encoded_bytes`]: #method.as_encoded_bytes
+    /// [`from_encoded_bytes_unchecked`]: #method.from_encoded_bytes_unchecked
+    // #[unstable(feature = "os_str_bytes", issue = "0")]
+    pub fn as_encoded_bytes(&self) -> &[u8] {
+        self.bytes()
+    }
+
+    /// Converts a slice of bytes to an `OsStr` slice without checking that the string contains
+    /// valid `OsStr`-encoded data.
+    ///
+    /// The byte encoding is an unspecified, platform-specific, self-synchronizing superset of
+    /// UTF-8. See [`as_encoded_bytes`] for more information.
+    ///
+    /// # Safety
+    ///
+    /// As the encoding is unspecified, callers must pass in bytes that originated as a
+    /// concatenation of validly encoded substrings, split only immediately before or after a
+    /// valid non-ASCII unit boundary (as produced by [`as_encoded_bytes`]). Arbitrary or
+    /// malformed bytes must not be passed in.
+    ///
+    /// [`as_encoded_bytes`]: #method.as_encoded_bytes
+    // #[unstable(feature = "os_str_bytes", issue = "0")]
+    pub unsafe fn from_encoded_bytes_unchecked(bytes: &[u8]) -> &OsStr<STD> {
+        OsStr::from_inner(STD::OsStr::from_bytes(bytes))
+    }
+
+    /// Takes a substring based on a range that corresponds to the return value of
+    /// [`as_encoded_bytes`].
+    ///
+    /// The range's start and end must lie on boundaries of [`as_encoded_bytes`]'s
+    /// return value, as would the boundaries of substrings previously returned by
+    /// `as_encoded_bytes` itself. In particular, this method is not suitable for
+    /// general substring matching, since the byte sequence may not fall on such
+    /// boundaries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` does not lie on valid boundaries.
+    ///
+    /// [`as_encoded_bytes`]: #method.as_encoded_bytes
+    // #[unstable(feature = "os_str_slice", issue = "0")]
+    pub fn slice_encoded_bytes<R: ops::RangeBounds<usize>>(&self, range: R) -> &OsStr<STD> {
+        let encoded = self.as_encoded_bytes();
+        let start = match range.start_bound() {
+            ops::Bound::Included(&i) => i,
+            ops::Bound::Excluded(&i) => i + 1,
+            ops::Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            ops::Bound::Included(&i) => i + 1,
+            ops::Bound::Excluded(&i) => i,
+            ops::Bound::Unbounded => encoded.len(),
+        };
+
+        assert!(start <= end, "slice index starts at {} but ends at {}", start, end);
+        assert!(self.inner.is_encoded_boundary(start), "byte index {} is not an OsStr boundary", start);
+        assert!(self.inner.is_encoded_boundary(end), "byte index {} is not an OsStr boundary", end);
+
+        unsafe { OsStr::from_encoded_bytes_unchecked(&encoded[start..end]) }
+    }
+
+    /// Checks if all characters in this string are within the ASCII range.
+    // #[unstable(feature = "osstring_ascii", issue = "0")]
+    pub fn is_ascii(&self) -> bool {
+        self.bytes().is_ascii()
+    }
+
+    /// Checks that two strings are an ASCII case-insensitive match.
+    ///
+    /// Same as `to_ascii_lowercase(a) == to_ascii_lowercase(b)`, but without
+    /// allocating and copying temporaries.
+    // #[unstable(feature = "osstring_ascii", issue = "0")]
+    pub fn eq_ignore_ascii_case(&self, other: &OsStr<STD>) -> bool {
+        self.bytes().eq_ignore_ascii_case(other.bytes())
+    }
+
+    /// Returns a copy of this string where each character is mapped to its
+    /// ASCII upper case equivalent.
+    ///
+    /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', but non-ASCII letters
+    /// are unchanged.
+    ///
+    /// To uppercase the value in-place, use [`OsString::make_ascii_uppercase`].
+    ///
+    /// [`OsString::make_ascii_uppercase`]: struct.OsString.html#method.make_ascii_uppercase
+    // #[unstable(feature = "osstring_ascii", issue = "0")]
+    pub fn to_ascii_uppercase(&self) -> OsString<STD> {
+        let mut s = self.to_os_string();
+        s.make_ascii_uppercase();
+        s
+    }
+
+    /// Returns a copy of this string where each character is mapped to its
+    /// ASCII lower case equivalent.
+    ///
+    /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', but non-ASCII letters
+    /// are unchanged.
+    ///
+    /// To lowercase the value in-place, use [`OsString::make_ascii_lowercase`].
+    ///
+    /// [`OsString::make_ascii_lowercase`]: struct.OsString.html#method.make_ascii_lowercase
+    // #[unstable(feature = "osstring_ascii", issue = "0")]
+    pub fn to_ascii_lowercase(&self) -> OsString<STD> {
+        let mut s = self.to_os_string();
+        s.make_ascii_lowercase();
+        s
+    }
 }
 
 // #[stable(feature = "box_from_os_str", since = "1.17.0")]
@@ -743,9 +969,9 @@ macro_rules! impl_cmp {
 
 impl_cmp!(OsString<STD>, OsStr<STD>);
 impl_cmp!(OsString<STD>, &'a OsStr<STD>);
-// impl_cmp!(Cow<'a, OsStr<STD>>, OsStr<STD>);
-// impl_cmp!(Cow<'a, OsStr<STD>>, &'b OsStr<STD>);
-// impl_cmp!(Cow<'a, OsStr<STD>>, OsString<STD>);
+impl_cmp!(Cow<'a, OsStr<STD>>, OsStr<STD>);
+impl_cmp!(Cow<'a, OsStr<STD>>, &'b OsStr<STD>);
+impl_cmp!(Cow<'a, OsStr<STD>>, OsString<STD>);
 
 // #[stable(feature = "rust1", since = "1.0.0")]
 impl<STD: Std> Hash for OsStr<STD> {
@@ -763,8 +989,71 @@ impl<STD: Std> fmt::Debug for OsStr<STD> {
 }
 
 impl<STD: Std> OsStr<STD> {
-    pub(crate) fn display(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Display::fmt(&self.inner, formatter)
+    /// Returns an object that implements [`Display`] for safely printing an
+    /// `OsStr` that may contain non-Unicode data.
+    ///
+    /// This is lossy: any non-Unicode sequences are replaced with
+    /// U+FFFD REPLACEMENT CHARACTER, which looks like this: &#65533;
+    ///
+    /// [`Display`]: ../fmt/trait.Display.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// let os_str = OsStr::new("Hello, world!");
+    /// println!("{}", os_str.display());
+    /// ```
+    // #[unstable(feature = "os_str_display", issue = "0")]
+    pub fn display(&self) -> Display<STD> {
+        Display { os_str: self }
+    }
+}
+
+/// Helper struct for safely printing an [`OsStr`] with [`format!`] and `{}`.
+///
+/// An `OsStr` might contain non-Unicode data, which this wrapper lossily
+/// converts by substituting U+FFFD REPLACEMENT CHARACTER for any invalid
+/// encoded-bytes sequences, mirroring [`OsStr::to_string_lossy`] without
+/// the intermediate allocation. Produced by the [`OsStr::display`] method.
+///
+/// [`OsStr::to_string_lossy`]: struct.OsStr.html#method.to_string_lossy
+/// [`OsStr::display`]: struct.OsStr.html#method.display
+// #[unstable(feature = "os_str_display", issue = "0")]
+pub struct Display<'a, STD: Std + 'a> {
+    os_str: &'a OsStr<STD>,
+}
+
+// #[unstable(feature = "os_str_display", issue = "0")]
+impl<'a, STD: Std> fmt::Debug for Display<'a, STD> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.os_str, formatter)
+    }
+}
+
+// #[unstable(feature = "os_str_display", issue = "0")]
+impl<'a, STD: Std> fmt::Display for Display<'a, STD> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut bytes = self.os_str.as_encoded_bytes();
+
+        loop {
+            match str::from_utf8(bytes) {
+                Ok(valid) => return formatter.write_str(valid),
+                Err(error) => {
+                    let valid_up_to = error.valid_up_to();
+                    // Safe because `from_utf8` just confirmed these bytes are valid UTF-8.
+                    let valid = unsafe { str::from_utf8_unchecked(&bytes[..valid_up_to]) };
+                    formatter.write_str(valid)?;
+                    formatter.write_str("\u{FFFD}")?;
+
+                    match error.error_len() {
+                        Some(len) => bytes = &bytes[valid_up_to + len..],
+                        None => return Ok(()),
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -813,6 +1102,26 @@ impl<STD: Std> AsRef<OsStr<STD>> for String {
     }
 }
 
+// #[stable(feature = "osstring_from_str", since = "1.16.0")]
+impl<STD: Std, T: AsRef<OsStr<STD>>> Extend<T> for OsString<STD> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for s in iter {
+            self.push(s.as_ref());
+        }
+    }
+}
+
+// #[stable(feature = "osstring_from_str", since = "1.16.0")]
+impl<STD: Std, T: AsRef<OsStr<STD>>> FromIterator<T> for OsString<STD> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> OsString<STD> {
+        let mut buf = OsString::new();
+        buf.extend(iter);
+        buf
+    }
+}
+
 impl<STD: Std> FromInner<STD::OsString> for OsString<STD> {
     fn from_inner(buf: STD::OsString) -> OsString<STD> {
         OsString { inner: buf }
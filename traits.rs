@@ -154,6 +154,24 @@ pub trait OsString<STD: Std>: Sized {
     fn reserve(&mut self, additional: usize);
     fn reserve_exact(&mut self, additional: usize);
     fn shrink_to_fit(&mut self);
+    fn shrink_to(&mut self, min_capacity: usize);
+
+    /// Shortens the buffer to `new_len` encoded bytes. The caller has already
+    /// checked that `new_len` falls on an encoded-bytes boundary.
+    fn truncate(&mut self, new_len: usize);
+
+    /// Exposes the raw storage of this `OsString` for in-place byte-wise
+    /// transforms.
+    ///
+    /// # Safety
+    ///
+    /// Every backend's encoding agrees with ASCII on bytes `0x00..=0x7F`, and
+    /// an ASCII byte never appears as part of a multi-unit sequence encoding a
+    /// non-ASCII character. Callers may therefore freely overwrite any byte in
+    /// the returned slice with another ASCII byte, but must not otherwise
+    /// change which positions hold ASCII bytes versus non-ASCII sequences.
+    unsafe fn as_mut_bytes(&mut self) -> &mut [u8];
+
     fn into_box(self) -> Box<STD::OsStr>;
     fn as_slice(&self) -> &STD::OsStr;
     fn from_box(boxed: Box<STD::OsStr>) -> Self;
@@ -168,6 +186,12 @@ pub trait OsStr<STD: Std>: Debug + Display {
     fn is_empty(&self) -> bool;
     fn len(&self) -> usize;
     fn as_bytes(&self) -> &[u8];
+
+    /// Reports whether `index` falls on a boundary between encoded-bytes
+    /// units, i.e. it does not bisect a multi-byte sequence or surrogate
+    /// pair. `index == 0` and `index == self.as_bytes().len()` are always
+    /// boundaries.
+    fn is_encoded_boundary(&self, index: usize) -> bool;
     fn into_box(&self) -> Box<Self>;
     fn into_arc(&self) -> Arc<Self>;
     fn into_rc(&self) -> Rc<Self>;